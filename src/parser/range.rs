@@ -246,6 +246,120 @@ where
     Range(i)
 }
 
+/// Items which can be compared ignoring ASCII case, used by [`range_no_case`][].
+///
+/// [`range_no_case`]: fn.range_no_case.html
+trait AsciiCaseless {
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool;
+}
+
+impl AsciiCaseless for u8 {
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        u8::eq_ignore_ascii_case(self, other)
+    }
+}
+
+impl AsciiCaseless for char {
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        char::eq_ignore_ascii_case(self, other)
+    }
+}
+
+pub struct RangeNoCase<I>
+where
+    I: RangeStream,
+{
+    expected: I::Range,
+    items: Vec<I::Item>,
+}
+
+impl<I> Parser for RangeNoCase<I>
+where
+    I: RangeStream,
+    I::Range: ::stream::Range,
+    I::Item: AsciiCaseless,
+{
+    type Input = I;
+    type Output = I::Range;
+    type PartialState = ();
+
+    #[inline]
+    fn parse_lazy(&mut self, input: &mut Self::Input) -> ConsumedResult<Self::Output, Self::Input> {
+        let position = input.position();
+        let before = input.checkpoint();
+
+        for expected_item in &self.items {
+            match input.uncons() {
+                Ok(item) => {
+                    if !item.eq_ignore_ascii_case(expected_item) {
+                        input.reset(before);
+                        return EmptyErr(I::Error::empty(position).into());
+                    }
+                }
+                Err(err) => {
+                    input.reset(before);
+                    return wrap_stream_error(input, err);
+                }
+            }
+        }
+
+        let distance = input.distance(&before);
+        input.reset(before);
+        match input.uncons_range(distance) {
+            Ok(range) => {
+                if distance == 0 {
+                    EmptyOk(range)
+                } else {
+                    ConsumedOk(range)
+                }
+            }
+            // We just unconsed exactly this much input above, so this can't fail.
+            Err(_) => unreachable!(),
+        }
+    }
+    fn add_error(&mut self, errors: &mut Tracked<<Self::Input as StreamOnce>::Error>) {
+        errors.error.add_expected(Info::Range(self.expected.clone()));
+    }
+}
+
+/// Zero-copy parser which reads a range of length `i.len()` and succeeds if `i` matches that
+/// range under an ASCII case fold, yielding the original (case-preserved) input range.
+///
+/// Modeled on winnow's `Caseless`: the comparison is case-insensitive but the returned range is
+/// the verbatim input, not `i`.
+///
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::range_no_case;
+/// # use combine::*;
+/// # fn main() {
+/// let mut parser = range_no_case("hello");
+/// let result = parser.parse("HeLLo world");
+/// assert_eq!(result, Ok(("HeLLo", " world")));
+/// let result = parser.parse("hel world");
+/// assert!(result.is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn range_no_case<I>(i: I::Range) -> RangeNoCase<I>
+where
+    I: RangeStream,
+    I::Range: RangeStreamOnce<Item = I::Item, Range = I::Range, Position = I::Position, Error = I::Error>
+        + Resetable,
+    I::Item: AsciiCaseless + Clone,
+{
+    let mut item_input = i.clone();
+    let mut items = Vec::new();
+    while let Ok(item) = item_input.uncons() {
+        items.push(item);
+    }
+
+    RangeNoCase {
+        expected: i,
+        items,
+    }
+}
+
 pub struct Take<I>(usize, PhantomData<fn(I) -> I>);
 impl<I> Parser for Take<I>
 where
@@ -289,6 +403,187 @@ where
     Take(n, PhantomData)
 }
 
+#[inline]
+fn u24_from_be_bytes(bytes: [u8; 3]) -> u32 {
+    u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]])
+}
+
+#[inline]
+fn u24_from_le_bytes(bytes: [u8; 3]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0])
+}
+
+#[inline]
+fn i24_from_be_bytes(bytes: [u8; 3]) -> i32 {
+    let sign = if bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+    i32::from_be_bytes([sign, bytes[0], bytes[1], bytes[2]])
+}
+
+#[inline]
+fn i24_from_le_bytes(bytes: [u8; 3]) -> i32 {
+    let sign = if bytes[2] & 0x80 != 0 { 0xff } else { 0 };
+    i32::from_le_bytes([bytes[0], bytes[1], bytes[2], sign])
+}
+
+macro_rules! fixed_width_range_parser {
+    ($struct_name:ident, $ty:ty, $size:expr, $convert:expr) => {
+        pub struct $struct_name<I>(PhantomData<fn(I) -> I>);
+
+        impl<I> Parser for $struct_name<I>
+        where
+            I: RangeStream,
+            I::Range: ::stream::Range + AsRef<[u8]>,
+        {
+            type Input = I;
+            type Output = $ty;
+            type PartialState = ();
+
+            #[inline]
+            fn parse_lazy(
+                &mut self,
+                input: &mut Self::Input,
+            ) -> ConsumedResult<Self::Output, Self::Input> {
+                uncons_range(input, $size).map(|bytes| {
+                    let mut buf = [0u8; $size];
+                    buf.copy_from_slice(bytes.as_ref());
+                    let convert: fn([u8; $size]) -> $ty = $convert;
+                    convert(buf)
+                })
+            }
+        }
+    };
+}
+
+macro_rules! fixed_width_range_fn {
+    ($(#[$doc:meta])* $fn_name:ident, $struct_name:ident) => {
+        $(#[$doc])*
+        #[inline(always)]
+        pub fn $fn_name<I>() -> $struct_name<I>
+        where
+            I: RangeStream,
+            I::Range: ::stream::Range + AsRef<[u8]>,
+        {
+            $struct_name(PhantomData)
+        }
+    };
+}
+
+fixed_width_range_parser!(BeU16, u16, 2, u16::from_be_bytes);
+fixed_width_range_parser!(BeU24, u32, 3, u24_from_be_bytes);
+fixed_width_range_parser!(BeU32, u32, 4, u32::from_be_bytes);
+fixed_width_range_parser!(BeU64, u64, 8, u64::from_be_bytes);
+fixed_width_range_parser!(LeU16, u16, 2, u16::from_le_bytes);
+fixed_width_range_parser!(LeU24, u32, 3, u24_from_le_bytes);
+fixed_width_range_parser!(LeU32, u32, 4, u32::from_le_bytes);
+fixed_width_range_parser!(LeU64, u64, 8, u64::from_le_bytes);
+
+fixed_width_range_parser!(BeI16, i16, 2, i16::from_be_bytes);
+fixed_width_range_parser!(BeI24, i32, 3, i24_from_be_bytes);
+fixed_width_range_parser!(BeI32, i32, 4, i32::from_be_bytes);
+fixed_width_range_parser!(BeI64, i64, 8, i64::from_be_bytes);
+fixed_width_range_parser!(LeI16, i16, 2, i16::from_le_bytes);
+fixed_width_range_parser!(LeI24, i32, 3, i24_from_le_bytes);
+fixed_width_range_parser!(LeI32, i32, 4, i32::from_le_bytes);
+fixed_width_range_parser!(LeI64, i64, 8, i64::from_le_bytes);
+
+fixed_width_range_parser!(BeF32, f32, 4, f32::from_be_bytes);
+fixed_width_range_parser!(BeF64, f64, 8, f64::from_be_bytes);
+fixed_width_range_parser!(LeF32, f32, 4, f32::from_le_bytes);
+fixed_width_range_parser!(LeF64, f64, 8, f64::from_le_bytes);
+
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 16-bit unsigned integer.
+    ///
+    /// ```
+    /// # extern crate combine;
+    /// # use combine::parser::range::be_u16;
+    /// # use combine::*;
+    /// # fn main() {
+    /// let result = be_u16().parse(&b"\x01\x02abc"[..]);
+    /// assert_eq!(result, Ok((0x0102, &b"abc"[..])));
+    /// # }
+    /// ```
+    be_u16, BeU16
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 24-bit unsigned integer into a `u32`.
+    be_u24, BeU24
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 32-bit unsigned integer.
+    be_u32, BeU32
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 64-bit unsigned integer.
+    be_u64, BeU64
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 16-bit unsigned integer.
+    le_u16, LeU16
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 24-bit unsigned integer into a `u32`.
+    le_u24, LeU24
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 32-bit unsigned integer.
+    le_u32, LeU32
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 64-bit unsigned integer.
+    le_u64, LeU64
+);
+
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 16-bit signed integer.
+    be_i16, BeI16
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 24-bit signed integer into an `i32`.
+    be_i24, BeI24
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 32-bit signed integer.
+    be_i32, BeI32
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 64-bit signed integer.
+    be_i64, BeI64
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 16-bit signed integer.
+    le_i16, LeI16
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 24-bit signed integer into an `i32`.
+    le_i24, LeI24
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 32-bit signed integer.
+    le_i32, LeI32
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 64-bit signed integer.
+    le_i64, LeI64
+);
+
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 32-bit IEEE-754 float.
+    be_f32, BeF32
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a big-endian 64-bit IEEE-754 float.
+    be_f64, BeF64
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 32-bit IEEE-754 float.
+    le_f32, LeF32
+);
+fixed_width_range_fn!(
+    /// Zero-copy parser which reads a little-endian 64-bit IEEE-754 float.
+    le_f64, LeF64
+);
+
 pub struct TakeWhile<I, F>(F, PhantomData<fn(I) -> I>);
 impl<I, F> Parser for TakeWhile<I, F>
 where
@@ -411,91 +706,470 @@ where
     TakeWhile1(f, PhantomData)
 }
 
-pub struct TakeUntilRange<I>(I::Range)
+/// Consumes items from `input` while `f` holds, stopping once `*matched` reaches `max` items
+/// (counting from whatever `*matched` already held on entry). Leaves the item that made `f`
+/// return `false` (if any) unconsumed.
+///
+/// Reports progress through `*matched` rather than the returned range's `Range::len()`: a range
+/// stream's items need not be a single stream-distance-unit wide (e.g. a multi-byte `char` in a
+/// `&str` stream), so the caller can't recover an item count from the range length, and needs an
+/// item count — not a distance — to track a `min`/`max` item budget across partial resumes.
+fn uncons_while_min_max<I, F>(
+    input: &mut I,
+    max: usize,
+    matched: &mut usize,
+    f: &mut F,
+) -> ConsumedResult<I::Range, I>
 where
-    I: RangeStream;
-impl<I> Parser for TakeUntilRange<I>
+    I: RangeStream,
+    F: FnMut(I::Item) -> bool,
+{
+    let before = input.checkpoint();
+
+    while *matched < max {
+        let look_ahead = input.checkpoint();
+        match input.uncons() {
+            Ok(item) => {
+                if f(item) {
+                    *matched += 1;
+                } else {
+                    input.reset(look_ahead);
+                    break;
+                }
+            }
+            Err(err) => {
+                // The end of a complete stream simply ends the run. On a partial stream there
+                // is not enough input to know whether we've hit `max` or the predicate would
+                // have failed, so the stream error reports that more input is needed.
+                if !input.is_partial() && err == ::error::StreamError::end_of_input() {
+                    break;
+                }
+                return wrap_stream_error(input, err);
+            }
+        }
+    }
+
+    let distance = input.distance(&before);
+    input.reset(before);
+    match input.uncons_range(distance) {
+        Ok(range) => {
+            if distance == 0 {
+                EmptyOk(range)
+            } else {
+                ConsumedOk(range)
+            }
+        }
+        // We just unconsed exactly this much input above, so this can't fail.
+        Err(_) => unreachable!(),
+    }
+}
+
+pub struct TakeWhileMinMax<I, F>(usize, usize, F, PhantomData<fn(I) -> I>);
+impl<I, F> Parser for TakeWhileMinMax<I, F>
 where
     I: RangeStream,
-    I::Range: PartialEq + ::stream::Range,
+    I::Item: PartialEq,
+    I::Range: ::stream::Range,
+    F: FnMut(I::Item) -> bool,
 {
     type Input = I;
     type Output = I::Range;
-    type PartialState = usize;
+    // (distance carried across partial resumes by `parse_partial_range`, items matched so far)
+    type PartialState = (usize, usize);
 
+    parse_mode!();
     #[inline]
-    fn parse_partial(
+    fn parse_mode_impl<M>(
         &mut self,
+        mode: M,
         input: &mut Self::Input,
-        to_consume: &mut Self::PartialState,
-    ) -> ConsumedResult<Self::Output, Self::Input> {
-        use stream::Range;
-
-        let len = self.0.len();
+        state: &mut Self::PartialState,
+    ) -> ConsumedResult<Self::Output, Self::Input>
+    where
+        M: ParseMode,
+    {
+        let min = self.0;
+        let max = self.1;
+        let (ref mut distance_state, ref mut matched) = *state;
+        let f = &mut self.2;
         let before = input.checkpoint();
-        let mut first_stream_error = None;
 
-        // Skip until the end of the last parse attempt
-        ctry!(uncons_range(input, *to_consume));
+        let result = parse_partial_range(
+            mode,
+            input,
+            distance_state,
+            (&mut *matched, f),
+            |input, (matched, f)| uncons_while_min_max(input, max, matched, f),
+            |input, (matched, f)| uncons_while_min_max(input, max, matched, f),
+        );
 
-        loop {
-            let look_ahead_input = input.checkpoint();
+        match result {
+            ConsumedOk(range) => {
+                if *matched < min {
+                    input.reset(before);
+                    EmptyErr(I::Error::empty(input.position()).into())
+                } else {
+                    *matched = 0;
+                    ConsumedOk(range)
+                }
+            }
+            EmptyOk(range) => {
+                if *matched < min {
+                    input.reset(before);
+                    EmptyErr(I::Error::empty(input.position()).into())
+                } else {
+                    *matched = 0;
+                    EmptyOk(range)
+                }
+            }
+            other => other,
+        }
+    }
+}
 
-            match input.uncons_range(len) {
-                Ok(xs) => {
-                    if xs == self.0 {
-                        let distance = input.distance(&before) - len;
-                        input.reset(before);
+/// Zero-copy parser which reads a range of `min..=max` tokens which satisfy `f`.
+///
+/// Stops as soon as `max` tokens have been consumed, and fails if fewer than `min` tokens
+/// satisfy `f`. This is the bounded middle ground between [`take_while`][] (0 or more) and
+/// [`take_while1`][] (1 or more) for fixed-or-bounded-width tokens, e.g. a 1-3 digit octet or a
+/// 2-hex-digit escape.
+///
+/// [`take_while`]: fn.take_while.html
+/// [`take_while1`]: fn.take_while1.html
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::take_while_min_max;
+/// # use combine::*;
+/// # fn main() {
+/// let mut parser = take_while_min_max(1, 3, |c: char| c.is_digit(10));
+/// let result = parser.parse("1234abc");
+/// assert_eq!(result, Ok(("123", "4abc")));
+/// let result = parser.parse("abc");
+/// assert!(result.is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn take_while_min_max<I, F>(min: usize, max: usize, f: F) -> TakeWhileMinMax<I, F>
+where
+    I: RangeStream,
+    I::Range: ::stream::Range,
+    F: FnMut(I::Item) -> bool,
+{
+    assert!(min <= max, "take_while_min_max: min must be <= max");
+    TakeWhileMinMax(min, max, f, PhantomData)
+}
 
-                        if let Ok(consumed) = input.uncons_range(distance) {
-                            if distance == 0 {
-                                return EmptyOk(consumed);
-                            } else {
-                                *to_consume = 0;
-                                return ConsumedOk(consumed);
-                            }
-                        }
+pub struct LengthData<I, L>
+where
+    I: RangeStream,
+    L: Parser<Input = I, Output = usize>,
+{
+    length: L,
+    _marker: PhantomData<fn(I) -> I>,
+}
 
-                        // We are guaranteed able to uncons to_consume characters here
-                        // because we've already done it on look_ahead_input.
-                        unreachable!();
+impl<I, L> Parser for LengthData<I, L>
+where
+    I: RangeStream,
+    L: Parser<Input = I, Output = usize>,
+{
+    type Input = I;
+    type Output = I::Range;
+    // (the already-decoded length, the state of the `length` parser while it's being decoded)
+    type PartialState = (Option<usize>, L::PartialState);
+
+    parse_mode!();
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Self::Input,
+        state: &mut Self::PartialState,
+    ) -> ConsumedResult<Self::Output, Self::Input>
+    where
+        M: ParseMode,
+    {
+        let (ref mut len, ref mut length_state) = *state;
+
+        let mut consumed = false;
+        if len.is_none() {
+            match self.length.parse_mode(mode, input, length_state) {
+                ConsumedOk(n) => {
+                    consumed = true;
+                    *len = Some(n);
+                }
+                EmptyOk(n) => *len = Some(n),
+                EmptyErr(err) => return EmptyErr(err),
+                ConsumedErr(err) => return ConsumedErr(err),
+            }
+        }
+
+        match take(len.expect("length already parsed")).parse_lazy(input) {
+            ConsumedOk(range) => {
+                *len = None;
+                ConsumedOk(range)
+            }
+            EmptyOk(range) => {
+                *len = None;
+                if consumed {
+                    ConsumedOk(range)
+                } else {
+                    EmptyOk(range)
+                }
+            }
+            ConsumedErr(err) => ConsumedErr(err),
+            EmptyErr(err) => {
+                if consumed {
+                    ConsumedErr(err.error)
+                } else {
+                    EmptyErr(err)
+                }
+            }
+        }
+    }
+    fn add_error(&mut self, errors: &mut Tracked<<Self::Input as StreamOnce>::Error>) {
+        self.length.add_error(errors)
+    }
+}
+
+/// Zero-copy parser which first parses a length with `length` and then consumes exactly that
+/// many items, returning the consumed range without parsing it any further.
+///
+/// [`length_value`][] additionally fully parses the returned range with a second parser.
+///
+/// [`length_value`]: fn.length_value.html
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::{be_u16, length_data};
+/// # use combine::*;
+/// # fn main() {
+/// let mut parser = length_data(be_u16().map(|n| n as usize));
+/// let result = parser.parse(&b"\x00\x03abcdef"[..]);
+/// assert_eq!(result, Ok((&b"abc"[..], &b"def"[..])));
+/// # }
+/// ```
+#[inline(always)]
+pub fn length_data<I, L>(length: L) -> LengthData<I, L>
+where
+    I: RangeStream,
+    L: Parser<Input = I, Output = usize>,
+{
+    LengthData {
+        length,
+        _marker: PhantomData,
+    }
+}
+
+pub struct LengthValue<I, L, P>
+where
+    I: RangeStream,
+    L: Parser<Input = I, Output = usize>,
+    P: Parser<Input = I::Range>,
+    I::Range: RangeStream<Item = I::Item, Range = I::Range, Position = I::Position, Error = I::Error>,
+{
+    length: LengthData<I, L>,
+    parser: P,
+}
+
+impl<I, L, P> Parser for LengthValue<I, L, P>
+where
+    I: RangeStream,
+    L: Parser<Input = I, Output = usize>,
+    P: Parser<Input = I::Range>,
+    I::Range: RangeStream<Item = I::Item, Range = I::Range, Position = I::Position, Error = I::Error>,
+{
+    type Input = I;
+    type Output = P::Output;
+    type PartialState = <LengthData<I, L> as Parser>::PartialState;
+
+    parse_mode!();
+    #[inline]
+    fn parse_mode_impl<M>(
+        &mut self,
+        mode: M,
+        input: &mut Self::Input,
+        state: &mut Self::PartialState,
+    ) -> ConsumedResult<Self::Output, Self::Input>
+    where
+        M: ParseMode,
+    {
+        let position = input.position();
+        let (range, consumed) = match self.length.parse_mode(mode, input, state) {
+            ConsumedOk(range) => (range, true),
+            EmptyOk(range) => (range, false),
+            EmptyErr(err) => return EmptyErr(err),
+            ConsumedErr(err) => return ConsumedErr(err),
+        };
+
+        match self.parser.parse(range) {
+            Ok((value, mut rest)) => {
+                if rest.uncons().is_ok() {
+                    return if consumed {
+                        ConsumedErr(I::Error::empty(position))
                     } else {
-                        // Reset the stream back to where it was when we entered the top of the loop
-                        input.reset(look_ahead_input);
+                        EmptyErr(I::Error::empty(position).into())
+                    };
+                }
 
-                        // Advance the stream by one item
-                        if input.uncons().is_err() {
-                            unreachable!();
-                        }
-                    }
+                if consumed {
+                    ConsumedOk(value)
+                } else {
+                    EmptyOk(value)
                 }
-                Err(first_error) => {
-                    // If we are unable to find a successful parse even after advancing with `uncons`
-                    // below we must reset the stream to its state before the first error.
-                    // If we don't we may try and match the range `::` against `:<EOF>` which would
-                    // fail as only one `:` is present at this parse attempt. But when we later resume
-                    // with more input we must start parsing again at the first time we errored so we
-                    // can see the entire `::`
-                    if first_stream_error.is_none() {
-                        first_stream_error = Some((first_error, input.distance(&before)));
-                    }
+            }
+            Err(_) => {
+                if consumed {
+                    ConsumedErr(I::Error::empty(position))
+                } else {
+                    EmptyErr(I::Error::empty(position).into())
+                }
+            }
+        }
+    }
+    fn add_error(&mut self, errors: &mut Tracked<<Self::Input as StreamOnce>::Error>) {
+        self.length.add_error(errors)
+    }
+}
+
+/// Zero-copy parser which first parses a length with `length`, consumes exactly that many
+/// items and then fully parses them with `parser`, erroring if `parser` leaves trailing input.
+///
+/// This is the length-prefixed counterpart to [`take_until_range`][], which only handles
+/// delimiter-terminated framing.
+///
+/// [`take_until_range`]: fn.take_until_range.html
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::{be_u16, length_value, take};
+/// # use combine::*;
+/// # fn main() {
+/// let mut parser = length_value(be_u16().map(|n| n as usize), take(3));
+/// let result = parser.parse(&b"\x00\x03abcrest"[..]);
+/// assert_eq!(result, Ok((&b"abc"[..], &b"rest"[..])));
+///
+/// let mut parser = length_value(be_u16().map(|n| n as usize), take(2));
+/// let result = parser.parse(&b"\x00\x03abcrest"[..]);
+/// assert!(result.is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn length_value<I, L, P>(length: L, parser: P) -> LengthValue<I, L, P>
+where
+    I: RangeStream,
+    L: Parser<Input = I, Output = usize>,
+    P: Parser<Input = I::Range>,
+    I::Range: RangeStream<Item = I::Item, Range = I::Range, Position = I::Position, Error = I::Error>,
+{
+    LengthValue {
+        length: length_data(length),
+        parser,
+    }
+}
 
-                    // Reset the stream back to where it was when we entered the top of the loop
-                    input.reset(look_ahead_input);
+/// Builds the KMP "failure function" (also known as the partial match table) for `needle`:
+/// `fail[i]` is the length of the longest proper prefix of `needle[0..=i]` that is also a
+/// suffix of it.
+fn kmp_failure_table<T>(needle: &[T]) -> Vec<usize>
+where
+    T: PartialEq,
+{
+    let mut fail = vec![0; needle.len()];
+    let mut len = 0;
+    let mut i = 1;
+    while i < needle.len() {
+        if needle[i] == needle[len] {
+            len += 1;
+            fail[i] = len;
+            i += 1;
+        } else if len != 0 {
+            len = fail[len - 1];
+        } else {
+            fail[i] = 0;
+            i += 1;
+        }
+    }
+    fail
+}
 
-                    // See if we can advance anyway
-                    if input.uncons().is_err() {
-                        let (first_error, first_error_distance) = first_stream_error.unwrap();
+pub struct TakeUntilRange<I>
+where
+    I: RangeStream,
+{
+    needle: Vec<I::Item>,
+    // The needle's length expressed in the stream's own distance units (e.g. bytes for a
+    // `&str`), which need not equal `needle.len()` when items are more than one unit wide.
+    needle_distance: usize,
+    fail: Vec<usize>,
+    _marker: PhantomData<fn(I) -> I>,
+}
 
-                        // Reset the stream
-                        input.reset(before);
-                        *to_consume = first_error_distance;
+impl<I> Parser for TakeUntilRange<I>
+where
+    I: RangeStream,
+    I::Range: ::stream::Range,
+    I::Item: PartialEq,
+{
+    type Input = I;
+    type Output = I::Range;
+    // (distance already skipped over from a previous partial parse, current KMP match length)
+    type PartialState = (usize, usize);
 
-                        // Return the original error if uncons failed
-                        return wrap_stream_error(input, first_error);
+    #[inline]
+    fn parse_partial(
+        &mut self,
+        input: &mut Self::Input,
+        state: &mut Self::PartialState,
+    ) -> ConsumedResult<Self::Output, Self::Input> {
+        let (ref mut to_consume, ref mut matched) = *state;
+        let needle_len = self.needle.len();
+        let before = input.checkpoint();
+
+        // Skip over the input we already stepped the automaton through on a previous,
+        // partial call. `*matched` already reflects where that left the automaton.
+        ctry!(uncons_range(input, *to_consume));
+
+        loop {
+            if *matched == needle_len {
+                let distance = input.distance(&before) - self.needle_distance;
+                input.reset(before);
+
+                if let Ok(range) = input.uncons_range(distance) {
+                    *to_consume = 0;
+                    *matched = 0;
+                    return if distance == 0 {
+                        EmptyOk(range)
+                    } else {
+                        ConsumedOk(range)
+                    };
+                }
+
+                // We are guaranteed able to uncons `distance` worth of input here because we've
+                // already done it while scanning up to this point.
+                unreachable!();
+            }
+
+            match input.uncons() {
+                Ok(item) => {
+                    // Fall back through the failure table instead of backtracking the
+                    // stream: this is what keeps the scan O(n + m) regardless of how many
+                    // times the needle almost-but-not-quite matches.
+                    while *matched > 0 && item != self.needle[*matched] {
+                        *matched = self.fail[*matched - 1];
                     }
+                    if item == self.needle[*matched] {
+                        *matched += 1;
+                    }
+                }
+                Err(err) => {
+                    // Not enough input to know whether the needle continues to match; record
+                    // how far we got as a stream distance rather than an item count (a range
+                    // stream's items, e.g. `char`, need not be a single stream-unit wide) so a
+                    // resume with more input can skip back to exactly this point.
+                    *to_consume = input.distance(&before);
+                    input.reset(before);
+                    return wrap_stream_error(input, err);
                 }
-            };
+            }
         }
     }
 }
@@ -524,8 +1198,149 @@ where
 pub fn take_until_range<I>(r: I::Range) -> TakeUntilRange<I>
 where
     I: RangeStream,
+    I::Range: ::stream::Range
+        + RangeStreamOnce<Item = I::Item, Range = I::Range, Position = I::Position, Error = I::Error>
+        + Resetable,
+    I::Item: PartialEq + Clone,
 {
-    TakeUntilRange(r)
+    use stream::Range;
+
+    let needle_distance = r.len();
+    let mut needle_input = r;
+    let mut needle = Vec::new();
+    while let Ok(item) = needle_input.uncons() {
+        needle.push(item);
+    }
+    let fail = kmp_failure_table(&needle);
+
+    TakeUntilRange {
+        needle,
+        needle_distance,
+        fail,
+        _marker: PhantomData,
+    }
+}
+
+pub struct TakeUntil<I, F>(F, bool, PhantomData<fn(I) -> I>);
+impl<I, F> Parser for TakeUntil<I, F>
+where
+    I: RangeStream,
+    I::Range: ::stream::Range,
+    F: FnMut(I::Item) -> bool,
+{
+    type Input = I;
+    type Output = I::Range;
+    type PartialState = usize;
+
+    #[inline]
+    fn parse_partial(
+        &mut self,
+        input: &mut Self::Input,
+        to_consume: &mut Self::PartialState,
+    ) -> ConsumedResult<Self::Output, Self::Input> {
+        let inclusive = self.1;
+        let before = input.checkpoint();
+
+        // Skip over the input already scanned (and found not to satisfy `f`) on a previous,
+        // partial call.
+        ctry!(uncons_range(input, *to_consume));
+
+        loop {
+            let before_item = input.checkpoint();
+            match input.uncons() {
+                Ok(item) => {
+                    if (self.0)(item) {
+                        // Measure from a checkpoint taken before the matching item rather than
+                        // subtracting a fixed unit from the total distance: the matching item
+                        // need not be a single stream-distance-unit wide (e.g. a multi-byte
+                        // `char` in a `&str` stream).
+                        let distance = if inclusive {
+                            input.distance(&before)
+                        } else {
+                            input.distance(&before_item)
+                        };
+                        input.reset(before);
+
+                        if let Ok(range) = input.uncons_range(distance) {
+                            *to_consume = 0;
+                            return if distance == 0 {
+                                EmptyOk(range)
+                            } else {
+                                ConsumedOk(range)
+                            };
+                        }
+
+                        // We've already unconsed `distance` worth of input above, so this can't
+                        // fail.
+                        unreachable!();
+                    }
+                }
+                Err(err) => {
+                    *to_consume = input.distance(&before);
+                    input.reset(before);
+                    return wrap_stream_error(input, err);
+                }
+            }
+        }
+    }
+}
+
+/// Zero-copy parser which reads a range of 0 or more tokens until the first one for which `f`
+/// returns `true`. That item is left unconsumed. Fails if the stream ends before `f` ever
+/// returns `true`.
+///
+/// [`take_until_inclusive`][] is the variant which also consumes the matching item.
+/// [`take_while`][] is its logical mirror, stopping when the predicate becomes `false` instead
+/// of `true`.
+///
+/// [`take_until_inclusive`]: fn.take_until_inclusive.html
+/// [`take_while`]: fn.take_while.html
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::take_until;
+/// # use combine::*;
+/// # fn main() {
+/// let mut parser = take_until(|c: char| c == ':');
+/// let result = parser.parse("key:value");
+/// assert_eq!(result, Ok(("key", ":value")));
+/// let result = parser.parse("no delimiter here");
+/// assert!(result.is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn take_until<I, F>(f: F) -> TakeUntil<I, F>
+where
+    I: RangeStream,
+    I::Range: ::stream::Range,
+    F: FnMut(I::Item) -> bool,
+{
+    TakeUntil(f, false, PhantomData)
+}
+
+/// Zero-copy parser which reads a range of 0 or more tokens up to and including the first one
+/// for which `f` returns `true`. Fails if the stream ends before `f` ever returns `true`.
+///
+/// [`take_until`][] is the variant which leaves the matching item unconsumed.
+///
+/// [`take_until`]: fn.take_until.html
+/// ```
+/// # extern crate combine;
+/// # use combine::parser::range::take_until_inclusive;
+/// # use combine::*;
+/// # fn main() {
+/// let mut parser = take_until_inclusive(|c: char| c == ':');
+/// let result = parser.parse("key:value");
+/// assert_eq!(result, Ok(("key:", "value")));
+/// # }
+/// ```
+#[inline(always)]
+pub fn take_until_inclusive<I, F>(f: F) -> TakeUntil<I, F>
+where
+    I: RangeStream,
+    I::Range: ::stream::Range,
+    F: FnMut(I::Item) -> bool,
+{
+    TakeUntil(f, true, PhantomData)
 }
 
 #[cfg(test)]
@@ -593,4 +1408,104 @@ mod tests {
             Ok(("⚙️🛠️🦀=🏎️⁘⁙⁘", "⁘⁙/⁘⁘⁙/⁘"))
         );
     }
+
+    #[test]
+    fn take_until_range_partial_resume() {
+        use stream::PartialStream;
+
+        let mut parser = take_until_range("::");
+        let mut state = Default::default();
+
+        // Only "key:" has arrived so far -- the needle "::" straddles the chunk boundary,
+        // with just its first ':' visible. The parser must report that more input is needed
+        // instead of failing outright, and must remember how far the KMP scan got in `state`.
+        match parser.parse_partial(&mut PartialStream("key:"), &mut state) {
+            EmptyErr(_) | ConsumedErr(_) => (),
+            _ => panic!("expected more input to be required, not a success"),
+        }
+
+        // The rest of the input has now arrived. Re-parsing the full buffer from the start
+        // must resume from `state` instead of rescanning, and find the needle spanning the
+        // original chunk boundary.
+        match parser.parse_partial(&mut PartialStream("key::value"), &mut state) {
+            ConsumedOk(range) => assert_eq!(range, "key"),
+            _ => panic!("expected take_until_range to resume successfully across the chunk boundary"),
+        }
+    }
+
+    #[test]
+    fn be_u16_test() {
+        let result = be_u16().parse(&b"\x01\x02rest"[..]);
+        assert_eq!(result, Ok((0x0102, &b"rest"[..])));
+    }
+
+    #[test]
+    fn le_u32_test() {
+        let result = le_u32().parse(&b"\x04\x03\x02\x01rest"[..]);
+        assert_eq!(result, Ok((0x0102_0304, &b"rest"[..])));
+    }
+
+    #[test]
+    fn be_i24_test() {
+        let result = be_i24().parse(&b"\xff\xff\xffrest"[..]);
+        assert_eq!(result, Ok((-1, &b"rest"[..])));
+    }
+
+    #[test]
+    fn be_f32_test() {
+        let result = be_f32().parse(&1.5f32.to_be_bytes()[..]);
+        assert_eq!(result, Ok((1.5, &b""[..])));
+    }
+
+    #[test]
+    fn length_data_test() {
+        let result = length_data(be_u16().map(|n| n as usize)).parse(&b"\x00\x03abcdef"[..]);
+        assert_eq!(result, Ok((&b"abc"[..], &b"def"[..])));
+    }
+
+    #[test]
+    fn length_value_trailing_input_errors() {
+        let result = length_value(be_u16().map(|n| n as usize), take(2)).parse(&b"\x00\x03abc"[..]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn range_no_case_test() {
+        let result = range_no_case("hello").parse("HeLLo world");
+        assert_eq!(result, Ok(("HeLLo", " world")));
+        let result = range_no_case("hello").parse("hel world");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn take_while_min_max_test() {
+        let result = take_while_min_max(1, 3, |c: char| c.is_digit(10)).parse("1234abc");
+        assert_eq!(result, Ok(("123", "4abc")));
+        let result = take_while_min_max(1, 3, |c: char| c.is_digit(10)).parse("abc");
+        assert!(result.is_err());
+        let result = take_while_min_max(2, 3, |c: char| c.is_digit(10)).parse("1abc");
+        assert!(result.is_err());
+        let result = take_while_min_max(1, 3, |c: char| !c.is_ascii()).parse("ααα123");
+        assert_eq!(result, Ok(("ααα", "123")));
+        let result = take_while_min_max(2, 3, |c: char| !c.is_ascii()).parse("α9");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn take_until_test() {
+        let result = take_until(|c: char| c == ':').parse("key:value");
+        assert_eq!(result, Ok(("key", ":value")));
+        let result = take_until(|c: char| c == ':').parse("no delimiter here");
+        assert!(result.is_err());
+        let result = take_until(|c: char| c == '🦀').parse("ab🦀cd");
+        assert_eq!(result, Ok(("ab", "🦀cd")));
+    }
+
+    #[test]
+    fn take_until_inclusive_test() {
+        let result = take_until_inclusive(|c: char| c == ':').parse("key:value");
+        assert_eq!(result, Ok(("key:", "value")));
+        let result = take_until_inclusive(|c: char| c == '🦀').parse("ab🦀cd");
+        assert_eq!(result, Ok(("ab🦀", "cd")));
+    }
 }